@@ -2,7 +2,7 @@ use parking_lot::Mutex;
 
 use crate::{
     AnyBox, AnyElement, AnyHandle, BorrowWindow, Bounds, Element, ElementId, Handle,
-    IntoAnyElement, LayoutId, Pixels, ViewContext, WindowContext,
+    IntoAnyElement, LayoutId, Pixels, Size, ViewContext, WindowContext,
 };
 use std::{marker::PhantomData, sync::Arc};
 
@@ -71,6 +71,21 @@ impl<V: 'static> Element for View<V> {
         })
     }
 
+    fn rebuild(
+        &mut self,
+        _: &mut (),
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<()>,
+    ) {
+        self.state.update(cx, |state, cx| {
+            // Builds a fresh element tree and replaces the retained one; the
+            // previous tree is not diffed against the new one.
+            let mut new_element = (self.render)(state, cx);
+            new_element.initialize(state, cx);
+            *element = new_element;
+        })
+    }
+
     fn layout(
         &mut self,
         _: &mut (),
@@ -122,6 +137,15 @@ impl<V: 'static, ParentV: 'static> Element for EraseViewState<V, ParentV> {
         ViewObject::initialize(&mut self.view, cx)
     }
 
+    fn rebuild(
+        &mut self,
+        _: &mut Self::ViewState,
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) {
+        ViewObject::rebuild(&mut self.view, element, cx)
+    }
+
     fn layout(
         &mut self,
         _: &mut Self::ViewState,
@@ -145,6 +169,7 @@ impl<V: 'static, ParentV: 'static> Element for EraseViewState<V, ParentV> {
 trait ViewObject: Send + Sync {
     fn entity_handle(&self) -> &AnyHandle;
     fn initialize(&mut self, cx: &mut WindowContext) -> AnyBox;
+    fn rebuild(&mut self, element: &mut AnyBox, cx: &mut WindowContext);
     fn layout(&mut self, element: &mut AnyBox, cx: &mut WindowContext) -> LayoutId;
     fn paint(&mut self, bounds: Bounds<Pixels>, element: &mut AnyBox, cx: &mut WindowContext);
 }
@@ -164,6 +189,17 @@ impl<V: 'static> ViewObject for View<V> {
         })
     }
 
+    fn rebuild(&mut self, element: &mut AnyBox, cx: &mut WindowContext) {
+        cx.with_element_id(self.state.entity_id, |_global_id, cx| {
+            self.state.update(cx, |state, cx| {
+                let element = element.downcast_mut::<AnyElement<V>>().unwrap();
+                let mut new_element = (self.render)(state, cx);
+                new_element.initialize(state, cx);
+                *element = new_element;
+            })
+        })
+    }
+
     fn layout(&mut self, element: &mut AnyBox, cx: &mut WindowContext) -> LayoutId {
         cx.with_element_id(self.state.entity_id, |_global_id, cx| {
             self.state.update(cx, |state, cx| {
@@ -219,6 +255,15 @@ impl Element for AnyView {
         self.view.lock().initialize(cx)
     }
 
+    fn rebuild(
+        &mut self,
+        _: &mut Self::ViewState,
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) {
+        self.view.lock().rebuild(element, cx)
+    }
+
     fn layout(
         &mut self,
         _: &mut Self::ViewState,
@@ -270,6 +315,15 @@ impl<ParentV: 'static> Element for EraseAnyViewState<ParentV> {
         self.view.view.lock().initialize(cx)
     }
 
+    fn rebuild(
+        &mut self,
+        _: &mut Self::ViewState,
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) {
+        self.view.view.lock().rebuild(element, cx)
+    }
+
     fn layout(
         &mut self,
         _: &mut Self::ViewState,
@@ -297,3 +351,283 @@ impl Clone for AnyView {
         }
     }
 }
+
+/// Ad-hoc modifiers that attach an extra attribute to a [`View`]/[`AnyView`] —
+/// a pinned id, a paint-time bound, a post-paint hook — without a new `Element`
+/// impl or a change to the render closure. Each modifier erases its view
+/// through the same path as [`AnyView`] (compare [`EraseAnyViewState`]), so a
+/// modified view still embeds in a parent view of any state type.
+pub trait ViewExt {
+    /// Override the view's [`Element::id`], pinning its identity across frames.
+    fn with_id(self, id: ElementId) -> WithId;
+
+    /// Clamp the bounds handed to the view at paint time to at most `max`. A
+    /// [`View`] ignores the bounds it is given when painting, so this does not
+    /// limit what the view paints; layout is unaffected.
+    fn bounded(self, max: Size<Pixels>) -> Bounded;
+
+    /// Run `hook` after the view paints, with the painted bounds and the window
+    /// context.
+    fn on_paint<F>(self, hook: F) -> OnPaint<F>
+    where
+        F: FnMut(Bounds<Pixels>, &mut WindowContext) + Send + Sync + 'static;
+}
+
+impl ViewExt for AnyView {
+    fn with_id(self, id: ElementId) -> WithId {
+        WithId { view: self, id }
+    }
+
+    fn bounded(self, max: Size<Pixels>) -> Bounded {
+        Bounded { view: self, max }
+    }
+
+    fn on_paint<F>(self, hook: F) -> OnPaint<F>
+    where
+        F: FnMut(Bounds<Pixels>, &mut WindowContext) + Send + Sync + 'static,
+    {
+        OnPaint { view: self, hook }
+    }
+}
+
+impl<V: 'static> ViewExt for View<V> {
+    fn with_id(self, id: ElementId) -> WithId {
+        self.into_any().with_id(id)
+    }
+
+    fn bounded(self, max: Size<Pixels>) -> Bounded {
+        self.into_any().bounded(max)
+    }
+
+    fn on_paint<F>(self, hook: F) -> OnPaint<F>
+    where
+        F: FnMut(Bounds<Pixels>, &mut WindowContext) + Send + Sync + 'static,
+    {
+        self.into_any().on_paint(hook)
+    }
+}
+
+pub struct WithId {
+    view: AnyView,
+    id: ElementId,
+}
+
+impl<ParentV: 'static> IntoAnyElement<ParentV> for WithId {
+    fn into_any(self) -> AnyElement<ParentV> {
+        AnyElement::new(WithIdState {
+            view: self.view,
+            id: self.id,
+            parent_view_state_type: PhantomData::<ParentV>,
+        })
+    }
+}
+
+struct WithIdState<ParentV> {
+    view: AnyView,
+    id: ElementId,
+    parent_view_state_type: PhantomData<ParentV>,
+}
+
+unsafe impl<ParentV> Send for WithIdState<ParentV> {}
+unsafe impl<ParentV> Sync for WithIdState<ParentV> {}
+
+impl<ParentV: 'static> Element for WithIdState<ParentV> {
+    type ViewState = ParentV;
+    type ElementState = AnyBox;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn initialize(
+        &mut self,
+        _: &mut Self::ViewState,
+        _: Option<Self::ElementState>,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) -> Self::ElementState {
+        self.view.view.lock().initialize(cx)
+    }
+
+    fn rebuild(
+        &mut self,
+        _: &mut Self::ViewState,
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) {
+        self.view.view.lock().rebuild(element, cx)
+    }
+
+    fn layout(
+        &mut self,
+        _: &mut Self::ViewState,
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) -> LayoutId {
+        self.view.view.lock().layout(element, cx)
+    }
+
+    fn paint(
+        &mut self,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::ViewState,
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) {
+        self.view.view.lock().paint(bounds, element, cx)
+    }
+}
+
+pub struct Bounded {
+    view: AnyView,
+    max: Size<Pixels>,
+}
+
+impl<ParentV: 'static> IntoAnyElement<ParentV> for Bounded {
+    fn into_any(self) -> AnyElement<ParentV> {
+        AnyElement::new(BoundedState {
+            view: self.view,
+            max: self.max,
+            parent_view_state_type: PhantomData::<ParentV>,
+        })
+    }
+}
+
+struct BoundedState<ParentV> {
+    view: AnyView,
+    max: Size<Pixels>,
+    parent_view_state_type: PhantomData<ParentV>,
+}
+
+unsafe impl<ParentV> Send for BoundedState<ParentV> {}
+unsafe impl<ParentV> Sync for BoundedState<ParentV> {}
+
+impl<ParentV: 'static> Element for BoundedState<ParentV> {
+    type ViewState = ParentV;
+    type ElementState = AnyBox;
+
+    fn id(&self) -> Option<ElementId> {
+        Element::id(&self.view)
+    }
+
+    fn initialize(
+        &mut self,
+        _: &mut Self::ViewState,
+        _: Option<Self::ElementState>,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) -> Self::ElementState {
+        self.view.view.lock().initialize(cx)
+    }
+
+    fn rebuild(
+        &mut self,
+        _: &mut Self::ViewState,
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) {
+        self.view.view.lock().rebuild(element, cx)
+    }
+
+    fn layout(
+        &mut self,
+        _: &mut Self::ViewState,
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) -> LayoutId {
+        self.view.view.lock().layout(element, cx)
+    }
+
+    fn paint(
+        &mut self,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::ViewState,
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) {
+        let size = Size {
+            width: bounds.size.width.min(self.max.width),
+            height: bounds.size.height.min(self.max.height),
+        };
+        let bounds = Bounds {
+            origin: bounds.origin,
+            size,
+        };
+        self.view.view.lock().paint(bounds, element, cx)
+    }
+}
+
+pub struct OnPaint<F> {
+    view: AnyView,
+    hook: F,
+}
+
+impl<ParentV: 'static, F> IntoAnyElement<ParentV> for OnPaint<F>
+where
+    F: FnMut(Bounds<Pixels>, &mut WindowContext) + Send + Sync + 'static,
+{
+    fn into_any(self) -> AnyElement<ParentV> {
+        AnyElement::new(OnPaintState {
+            view: self.view,
+            hook: self.hook,
+            parent_view_state_type: PhantomData::<ParentV>,
+        })
+    }
+}
+
+struct OnPaintState<ParentV, F> {
+    view: AnyView,
+    hook: F,
+    parent_view_state_type: PhantomData<ParentV>,
+}
+
+unsafe impl<ParentV, F> Send for OnPaintState<ParentV, F> {}
+unsafe impl<ParentV, F> Sync for OnPaintState<ParentV, F> {}
+
+impl<ParentV: 'static, F> Element for OnPaintState<ParentV, F>
+where
+    F: FnMut(Bounds<Pixels>, &mut WindowContext) + Send + Sync + 'static,
+{
+    type ViewState = ParentV;
+    type ElementState = AnyBox;
+
+    fn id(&self) -> Option<ElementId> {
+        Element::id(&self.view)
+    }
+
+    fn initialize(
+        &mut self,
+        _: &mut Self::ViewState,
+        _: Option<Self::ElementState>,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) -> Self::ElementState {
+        self.view.view.lock().initialize(cx)
+    }
+
+    fn rebuild(
+        &mut self,
+        _: &mut Self::ViewState,
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) {
+        self.view.view.lock().rebuild(element, cx)
+    }
+
+    fn layout(
+        &mut self,
+        _: &mut Self::ViewState,
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) -> LayoutId {
+        self.view.view.lock().layout(element, cx)
+    }
+
+    fn paint(
+        &mut self,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::ViewState,
+        element: &mut Self::ElementState,
+        cx: &mut ViewContext<Self::ViewState>,
+    ) {
+        self.view.view.lock().paint(bounds, element, cx);
+        (self.hook)(bounds, cx);
+    }
+}
@@ -0,0 +1,102 @@
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+use crate::{Handle, ViewContext, WindowContext};
+
+/// A closure queued from another thread to be run on the main thread with real
+/// [`WindowContext`] access when its [`WorkQueue`] is next drained.
+pub type MainThreadJob = Box<dyn FnOnce(&mut WindowContext) + Send>;
+
+/// Wakes the frame loop so a freshly enqueued [`WorkQueue`] job is drained
+/// promptly instead of waiting for the next input-driven redraw.
+pub trait EventLoopProxy: Send + Sync {
+    fn wake(&self);
+}
+
+/// An [`EventLoopProxy`] backed by a wake callback supplied at construction,
+/// such as the OS event loop's wake function.
+pub struct PlatformEventLoopProxy {
+    wake: Box<dyn Fn() + Send + Sync>,
+}
+
+impl PlatformEventLoopProxy {
+    pub fn new(wake: impl Fn() + Send + Sync + 'static) -> Self {
+        Self {
+            wake: Box::new(wake),
+        }
+    }
+}
+
+impl EventLoopProxy for PlatformEventLoopProxy {
+    fn wake(&self) {
+        (self.wake)();
+    }
+}
+
+/// A thread-safe queue of work to run on the main thread. Background tasks push
+/// closures here and signal the event-loop proxy to wake rendering; the queued
+/// closures run only when a caller passes the window's context to
+/// [`WorkQueue::drain`]. The queue does not drain itself.
+#[derive(Clone)]
+pub struct WorkQueue {
+    jobs: Arc<Mutex<Vec<MainThreadJob>>>,
+    proxy: Arc<dyn EventLoopProxy>,
+}
+
+impl WorkQueue {
+    pub fn new(proxy: Arc<dyn EventLoopProxy>) -> Self {
+        Self {
+            jobs: Default::default(),
+            proxy,
+        }
+    }
+
+    /// Enqueue `f` to run on the main thread and wake the frame loop. Safe to
+    /// call from any thread without holding a context borrow.
+    pub fn push(&self, f: impl FnOnce(&mut WindowContext) + Send + 'static) {
+        self.jobs.lock().push(Box::new(f));
+        self.proxy.wake();
+    }
+
+    /// Run every queued job against `cx`, oldest first, leaving the queue
+    /// empty. Jobs enqueued while draining wait for the next call.
+    pub fn drain(&self, cx: &mut WindowContext) {
+        let jobs = std::mem::take(&mut *self.jobs.lock());
+        for job in jobs {
+            job(cx);
+        }
+    }
+}
+
+impl WindowContext {
+    /// A cloneable, [`Send`] handle to this window's [`WorkQueue`]. Hand this to
+    /// a background task before it leaves the main thread so it can enqueue work
+    /// without a [`WindowContext`] (which is not `Send`) of its own.
+    pub fn work_queue(&self) -> WorkQueue {
+        self.work_queue.clone()
+    }
+
+    /// Defer `f` onto the window's [`WorkQueue`] to run when it is next drained.
+    /// Convenience for callers that already hold a context on the main thread;
+    /// off-thread callers push through a [`WorkQueue`] handle instead.
+    pub fn spawn_on_main(&self, f: impl FnOnce(&mut WindowContext) + Send + 'static) {
+        self.work_queue.push(f);
+    }
+}
+
+impl<V: 'static> Handle<V> {
+    /// Enqueue an update to this handle's view state to run on the main thread,
+    /// waking the frame loop. The mirror of [`Handle::update`] for callers that
+    /// are off the main thread and therefore cannot borrow a context; they hold
+    /// a [`WorkQueue`] handle (from [`WindowContext::work_queue`]) instead.
+    pub fn update_on_main(
+        &self,
+        work_queue: &WorkQueue,
+        update: impl FnOnce(&mut V, &mut ViewContext<V>) + Send + 'static,
+    ) {
+        let this = self.clone();
+        work_queue.push(move |cx| {
+            this.update(cx, |state, cx| update(state, cx));
+        });
+    }
+}